@@ -0,0 +1,233 @@
+//! Thread-safe variant of [`Mrc`](crate::mrc::Mrc) for stores shared across web-worker boundaries
+//! or multi-threaded SSR.
+//!
+//! `Mrc` is `!Send`/`!Sync` because it's built on `Rc<RefCell<T>>`. `AtomicMrc` gives up nothing
+//! in the uncontended, single-threaded case (a shared borrow is one atomic fetch-add, an
+//! exclusive borrow is one compare-exchange) while still being safe to clone across threads, the
+//! way the `atomic_refcell` crate builds a single-word atomic refcount on top of `Arc`.
+//!
+//! ```ignore
+//! #[derive(Default, Clone, PartialEq, Store)]
+//! struct State {
+//!     data: AtomicMrc<MyLargeData>,
+//! }
+//! ```
+use std::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+    sync::{
+        atomic::{AtomicU32, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+fn nonce() -> u32 {
+    static NONCE: AtomicU32 = AtomicU32::new(0);
+    NONCE.fetch_add(1, Ordering::Relaxed).wrapping_add(1)
+}
+
+/// Set while an exclusive borrow is active. The remaining bits count active shared borrows.
+const WRITING: usize = 1 << (usize::BITS - 1);
+
+#[derive(Debug)]
+struct Shared<T> {
+    value: UnsafeCell<T>,
+    state: AtomicUsize,
+}
+
+// SAFETY: `value` is only ever accessed through `AtomicMrcRef`/`AtomicMrcRefMut`, which are handed
+// out by `AtomicMrc::borrow`/`borrow_mut` under the mutual exclusion enforced by `state` (same
+// contract as `std::sync::RwLock`).
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send + Sync> Sync for Shared<T> {}
+
+/// Thread-safe mutable reference counted wrapper type that works well with Yewdux.
+///
+/// This is basically a wrapper over `Arc<T>` with a single atomic refcount guarding interior
+/// mutability, with the same simple nonce-based change detection as [`Mrc`](crate::mrc::Mrc).
+#[derive(Debug)]
+pub struct AtomicMrc<T> {
+    inner: Arc<Shared<T>>,
+    nonce: AtomicU32,
+}
+
+impl<T: 'static> AtomicMrc<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Arc::new(Shared {
+                value: UnsafeCell::new(value),
+                state: AtomicUsize::new(0),
+            }),
+            nonce: AtomicU32::new(nonce()),
+        }
+    }
+
+    pub fn with_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut this = self.borrow_mut();
+        f(this.deref_mut())
+    }
+
+    /// Provide a shared reference to the inner value. A single atomic fetch-add in the
+    /// uncontended case.
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    pub fn borrow(&self) -> AtomicMrcRef<'_, T> {
+        let previous = self.inner.state.fetch_add(1, Ordering::Acquire);
+
+        if previous & WRITING != 0 {
+            self.inner.state.fetch_sub(1, Ordering::Release);
+            panic!("already mutably borrowed: AtomicMrc<T>");
+        }
+
+        AtomicMrcRef {
+            shared: &self.inner,
+        }
+    }
+
+    /// Provide a mutable reference to inner value. A single compare-exchange in the uncontended
+    /// case.
+    ///
+    /// Panics if the value is currently borrowed, mutably or otherwise.
+    pub fn borrow_mut(&mut self) -> AtomicMrcRefMut<'_, T> {
+        if self
+            .inner
+            .state
+            .compare_exchange(0, WRITING, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            panic!("already borrowed: AtomicMrc<T>");
+        }
+
+        // Mark as changed.
+        self.nonce.store(nonce(), Ordering::Relaxed);
+
+        AtomicMrcRefMut {
+            shared: &self.inner,
+        }
+    }
+}
+
+/// A borrow guard returned from [AtomicMrc::borrow].
+pub struct AtomicMrcRef<'a, T> {
+    shared: &'a Shared<T>,
+}
+
+impl<'a, T> Deref for AtomicMrcRef<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: holding this guard guarantees no exclusive borrow is active.
+        unsafe { &*self.shared.value.get() }
+    }
+}
+
+impl<'a, T> Drop for AtomicMrcRef<'a, T> {
+    fn drop(&mut self) {
+        self.shared.state.fetch_sub(1, Ordering::Release);
+    }
+}
+
+/// A mutable borrow guard returned from [AtomicMrc::borrow_mut].
+pub struct AtomicMrcRefMut<'a, T> {
+    shared: &'a Shared<T>,
+}
+
+impl<'a, T> Deref for AtomicMrcRefMut<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: holding this guard guarantees exclusive access to the value.
+        unsafe { &*self.shared.value.get() }
+    }
+}
+
+impl<'a, T> DerefMut for AtomicMrcRefMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: holding this guard guarantees exclusive access to the value.
+        unsafe { &mut *self.shared.value.get() }
+    }
+}
+
+impl<'a, T> Drop for AtomicMrcRefMut<'a, T> {
+    fn drop(&mut self) {
+        // Clear only the write bit. A concurrent `borrow` (the documented panic path) does an
+        // unconditional `fetch_add(1)` before checking `WRITING`, then backs out with
+        // `fetch_sub(1)` if it lost the race; blindly `store(0)`-ing here could land between that
+        // add and its matching sub, wiping out the reader's in-flight increment and underflowing
+        // the state on its `fetch_sub`.
+        self.shared.state.fetch_sub(WRITING, Ordering::Release);
+    }
+}
+
+impl<T> Clone for AtomicMrc<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            nonce: AtomicU32::new(self.nonce.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl<T> PartialEq for AtomicMrc<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+            && self.nonce.load(Ordering::Relaxed) == other.nonce.load(Ordering::Relaxed)
+    }
+}
+
+impl<T: Default + 'static> Default for AtomicMrc<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn with_mut_mutates_value() {
+        let mut value = AtomicMrc::new(0);
+
+        value.with_mut(|v| *v += 1);
+
+        assert_eq!(*value.borrow(), 1);
+    }
+
+    #[test]
+    fn borrow_mut_bumps_nonce() {
+        let mut value = AtomicMrc::new(0);
+        let before = value.clone();
+
+        value.with_mut(|v| *v += 1);
+
+        assert!(value != before);
+    }
+
+    #[test]
+    #[should_panic]
+    fn borrow_mut_panics_while_already_borrowed() {
+        let value = AtomicMrc::new(0);
+        let mut other = value.clone();
+        let _guard = value.borrow();
+
+        other.borrow_mut();
+    }
+
+    #[test]
+    fn clones_are_usable_across_threads() {
+        let mut value = AtomicMrc::new(0);
+        let mut other = value.clone();
+
+        let handle = thread::spawn(move || {
+            other.with_mut(|v| *v += 1);
+        });
+        handle.join().unwrap();
+
+        value.with_mut(|v| *v += 1);
+
+        assert_eq!(*value.borrow(), 2);
+    }
+}